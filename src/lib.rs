@@ -5,6 +5,51 @@ use rand::prelude::*;
 use std::fs::File;
 use std::path::Path;
 
+/// Strategy used to pick the initial cluster centroids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitMethod {
+    /// Pick `k` distinct data rows uniformly at random.
+    Random,
+    /// k-means++ seeding: spread the initial centers by sampling each new
+    /// center with probability proportional to its squared distance to the
+    /// nearest already-chosen center.
+    KmeansPlusPlus,
+}
+
+/// Outcome of a clustering run, including the quality measure used to pick it.
+#[derive(Debug, Clone)]
+pub struct ClusterResult {
+    /// Per-point cluster labels.
+    pub labels: Vec<usize>,
+    /// Final centroids, one row per cluster.
+    pub centroids: Array2<f32>,
+    /// Total inertia (sum of point-to-centroid distances under the chosen
+    /// metric; squared distances for Euclidean).
+    pub inertia: f32,
+}
+
+/// Dissimilarity function used during assignment on the numeric path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Squared Euclidean distance.
+    Euclidean,
+    /// Sum of absolute coordinate differences.
+    Manhattan,
+    /// Cosine distance, `1 - (a·b)/(‖a‖‖b‖)`.
+    Cosine,
+}
+
+/// One point's centroid distances ranked nearest-first, used by the
+/// capacity-constrained assignment in [`DataSet::equal_kmeans`].
+struct PointRegret {
+    /// Index of the point in the dataset.
+    index: usize,
+    /// `(squared distance, centroid index)` pairs sorted nearest-first.
+    ranked: Vec<(f32, usize)>,
+    /// Gap between the nearest and second-nearest centroid.
+    regret: f32,
+}
+
 /// Represents a numerical dataset loaded from a TSV file
 #[derive(Debug, Clone)]
 pub struct DataSet {
@@ -62,6 +107,237 @@ impl DataSet {
         ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
     }
 
+    /// Squared Euclidean distance between two equal-length coordinate slices
+    #[inline]
+    fn sq_dist(x: &[f32], y: &[f32]) -> f32 {
+        x.iter().zip(y).fold(0.0, |d, (a, b)| d + (a - b).powi(2))
+    }
+
+    /// Dissimilarity between two coordinate slices under the chosen metric.
+    #[inline]
+    fn metric_dist(metric: Metric, x: &[f32], y: &[f32]) -> f32 {
+        match metric {
+            Metric::Euclidean => Self::sq_dist(x, y),
+            Metric::Manhattan => x.iter().zip(y).fold(0.0, |d, (a, b)| d + (a - b).abs()),
+            Metric::Cosine => {
+                let mut dot = 0.0;
+                let mut na = 0.0;
+                let mut nb = 0.0;
+                for (a, b) in x.iter().zip(y) {
+                    dot += a * b;
+                    na += a * a;
+                    nb += b * b;
+                }
+                let denom = na.sqrt() * nb.sqrt();
+                if denom > 0.0 {
+                    1.0 - dot / denom
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+
+    /// Pick `k` initial centroids from `data` using the requested strategy.
+    fn init_centroids(
+        data: &Array2<f32>,
+        k: usize,
+        init: InitMethod,
+        rng: &mut impl Rng,
+    ) -> Array2<f32> {
+        let nrows = data.nrows();
+        let ncols = data.ncols();
+        let mut centroids = Array2::<f32>::zeros((k, ncols));
+
+        match init {
+            InitMethod::Random => {
+                let mut indices: Vec<usize> = (0..nrows).collect();
+                indices.shuffle(rng);
+                for (ci, &idx) in indices.iter().take(k).enumerate() {
+                    centroids.row_mut(ci).assign(&data.row(idx));
+                }
+            }
+            InitMethod::KmeansPlusPlus => {
+                let idxs = kmeans_plus_plus(nrows, k, rng, |a, b| {
+                    Self::sq_dist(
+                        data.row(a).as_slice().unwrap(),
+                        data.row(b).as_slice().unwrap(),
+                    )
+                });
+                for (ci, &idx) in idxs.iter().enumerate() {
+                    centroids.row_mut(ci).assign(&data.row(idx));
+                }
+            }
+        }
+
+        centroids
+    }
+
+    /// Perform K-means clustering on the first `ncols` columns.
+    ///
+    /// This generalizes [`kmeans3d`](Self::kmeans3d) to an arbitrary number of
+    /// features: centroids live in a `k × ncols` array and assignment picks the
+    /// nearest centroid under the chosen [`Metric`]. `init` selects how the
+    /// starting centroids are seeded.
+    pub fn kmeans(
+        &self,
+        k: usize,
+        ncols: usize,
+        max_iter: usize,
+        init: InitMethod,
+        metric: Metric,
+    ) -> Result<Vec<usize>> {
+        let data = self.numeric_view(ncols);
+        let nrows = data.nrows();
+
+        if nrows < k {
+            return Err(anyhow!("Not enough data points ({}) for {} clusters", nrows, k));
+        }
+
+        let mut rng = thread_rng();
+        let (assignments, _) = Self::kmeans_run(&data, k, max_iter, init, metric, &mut rng);
+        Ok(assignments)
+    }
+
+    /// Run clustering `n_init` times with different seeds and keep the solution
+    /// with the lowest total inertia (sum of distances from each point to its
+    /// assigned centroid under `metric`; squared distances for Euclidean). The returned [`ClusterResult`] carries the
+    /// winning labels, centroids and inertia so callers can judge quality.
+    pub fn kmeans_best(
+        &self,
+        k: usize,
+        ncols: usize,
+        max_iter: usize,
+        init: InitMethod,
+        metric: Metric,
+        n_init: usize,
+    ) -> Result<ClusterResult> {
+        let data = self.numeric_view(ncols);
+        let nrows = data.nrows();
+
+        if nrows < k {
+            return Err(anyhow!("Not enough data points ({}) for {} clusters", nrows, k));
+        }
+
+        let mut rng = thread_rng();
+        let mut best: Option<ClusterResult> = None;
+        for _ in 0..n_init.max(1) {
+            let (labels, centroids) = Self::kmeans_run(&data, k, max_iter, init, metric, &mut rng);
+            let inertia = Self::inertia(&data, &labels, &centroids, metric);
+            if best.as_ref().is_none_or(|b| inertia < b.inertia) {
+                best = Some(ClusterResult { labels, centroids, inertia });
+            }
+        }
+
+        Ok(best.expect("at least one run"))
+    }
+
+    /// Total inertia of an assignment: the sum over points of the distance to
+    /// their assigned centroid, measured with the same `metric` used during
+    /// assignment so best-of-N selection stays consistent with it.
+    fn inertia(data: &Array2<f32>, labels: &[usize], centroids: &Array2<f32>, metric: Metric) -> f32 {
+        data.outer_iter()
+            .zip(labels)
+            .map(|(row, &c)| {
+                Self::metric_dist(metric, row.as_slice().unwrap(), centroids.row(c).as_slice().unwrap())
+            })
+            .sum()
+    }
+
+    /// A single k-means run over `data`, returning the final labels and centroids.
+    ///
+    /// Euclidean assignment uses a batched ndarray-broadcast distance matrix;
+    /// other metrics fall back to a per-point/per-centroid loop. The centroid
+    /// update is the coordinate mean, L2-normalized for cosine.
+    fn kmeans_run(
+        data: &Array2<f32>,
+        k: usize,
+        max_iter: usize,
+        init: InitMethod,
+        metric: Metric,
+        rng: &mut impl Rng,
+    ) -> (Vec<usize>, Array2<f32>) {
+        let nrows = data.nrows();
+        let ncols = data.ncols();
+        let mut centroids = Self::init_centroids(data, k, init, rng);
+        let mut assignments = vec![0usize; nrows];
+
+        for _ in 0..max_iter {
+            // Step 1: assign every point to its nearest centroid. Euclidean
+            // takes the batched broadcast path — the `n × 1 × d` points against
+            // the `k × d` centroids give an `n × k × d` difference tensor whose
+            // squared feature-sum is the `n × k` distance matrix. Other metrics
+            // fall back to a per-point/per-centroid loop.
+            if metric == Metric::Euclidean {
+                let dists = (&data.view().insert_axis(Axis(1)) - &centroids)
+                    .mapv(|c| c.powi(2))
+                    .sum_axis(Axis(2));
+                for (i, row) in dists.outer_iter().enumerate() {
+                    let mut best_cluster = 0;
+                    let mut best_dist = f32::MAX;
+                    for (ci, &d) in row.iter().enumerate() {
+                        if d < best_dist {
+                            best_dist = d;
+                            best_cluster = ci;
+                        }
+                    }
+                    assignments[i] = best_cluster;
+                }
+            } else {
+                for (i, row) in data.outer_iter().enumerate() {
+                    let point = row.as_slice().unwrap();
+                    let mut best_cluster = 0;
+                    let mut best_dist = f32::MAX;
+                    for (ci, c_row) in centroids.outer_iter().enumerate() {
+                        let d = Self::metric_dist(metric, point, c_row.as_slice().unwrap());
+                        if d < best_dist {
+                            best_dist = d;
+                            best_cluster = ci;
+                        }
+                    }
+                    assignments[i] = best_cluster;
+                }
+            }
+
+            // Step 2: update centroids as mean of assigned points
+            let mut new_centroids = Array2::<f32>::zeros((k, ncols));
+            let mut counts = vec![0usize; k];
+
+            for (i, row) in data.outer_iter().enumerate() {
+                let c = assignments[i];
+                new_centroids.row_mut(c).zip_mut_with(&row, |n, &x| *n += x);
+                counts[c] += 1;
+            }
+
+            for (ci, &count) in counts.iter().enumerate() {
+                if count > 0 {
+                    new_centroids.row_mut(ci).mapv_inplace(|x| x / count as f32);
+                    if metric == Metric::Cosine {
+                        // Cosine works on directions: renormalize the mean.
+                        let norm = new_centroids.row(ci).dot(&new_centroids.row(ci)).sqrt();
+                        if norm > 0.0 {
+                            new_centroids.row_mut(ci).mapv_inplace(|x| x / norm);
+                        }
+                    }
+                } else {
+                    // reinitialize empty cluster randomly
+                    let idx = rng.gen_range(0..nrows);
+                    new_centroids.row_mut(ci).assign(&data.row(idx));
+                }
+            }
+
+            let diff = (&centroids - &new_centroids)
+                .mapv(|x| x.abs())
+                .sum();
+            if diff < 1e-4 {
+                break;
+            }
+            centroids = new_centroids;
+        }
+
+        (assignments, centroids)
+    }
+
     /// Perform K-means clustering on the first three columns
     pub fn kmeans3d(&self, k: usize, max_iter: usize) -> Result<Vec<usize>> {
         let data = self.numeric_view(3);
@@ -113,9 +389,9 @@ impl DataSet {
                 counts[c] += 1;
             }
 
-            for ci in 0..k {
-                if counts[ci] > 0 {
-                    new_centroids.row_mut(ci).mapv_inplace(|x| x / counts[ci] as f32);
+            for (ci, &count) in counts.iter().enumerate() {
+                if count > 0 {
+                    new_centroids.row_mut(ci).mapv_inplace(|x| x / count as f32);
                 } else {
                     // reinitialize empty cluster randomly
                     let idx = rng.gen_range(0..nrows);
@@ -135,11 +411,261 @@ impl DataSet {
 
         Ok(assignments)
     }
+
+    /// K-means variant that produces clusters of (near-)equal cardinality.
+    ///
+    /// Each cluster is capped at `ceil(n / k)` members. Points are assigned in
+    /// order of decreasing "regret" — the gap between their nearest and
+    /// second-nearest centroid — so the points that care most about their
+    /// preferred cluster claim it first, overflowing to the next-nearest
+    /// centroid with free capacity.
+    pub fn equal_kmeans(
+        &self,
+        k: usize,
+        ncols: usize,
+        max_iter: usize,
+        init: InitMethod,
+    ) -> Result<Vec<usize>> {
+        let data = self.numeric_view(ncols);
+        let nrows = data.nrows();
+
+        if nrows < k {
+            return Err(anyhow!("Not enough data points ({}) for {} clusters", nrows, k));
+        }
+
+        let mut rng = thread_rng();
+        let mut centroids = Self::init_centroids(&data, k, init, &mut rng);
+        let ncols = data.ncols();
+        let capacity = nrows.div_ceil(k);
+
+        let mut assignments = vec![0usize; nrows];
+
+        for _ in 0..max_iter {
+            // Step 1: capacity-constrained assignment.
+            // Distances of every point to every centroid, plus its regret.
+            let mut order: Vec<PointRegret> = Vec::with_capacity(nrows);
+            for (i, row) in data.outer_iter().enumerate() {
+                let point = row.as_slice().unwrap();
+                let mut ranked: Vec<(f32, usize)> = centroids
+                    .outer_iter()
+                    .enumerate()
+                    .map(|(ci, c)| (Self::sq_dist(point, c.as_slice().unwrap()), ci))
+                    .collect();
+                ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+                let regret = if ranked.len() > 1 { ranked[1].0 - ranked[0].0 } else { 0.0 };
+                order.push(PointRegret { index: i, ranked, regret });
+            }
+            // Largest regret first.
+            order.sort_by(|a, b| b.regret.total_cmp(&a.regret));
+
+            let mut counts = vec![0usize; k];
+            for pr in &order {
+                for &(_, ci) in &pr.ranked {
+                    if counts[ci] < capacity {
+                        assignments[pr.index] = ci;
+                        counts[ci] += 1;
+                        break;
+                    }
+                }
+            }
+
+            // Step 2: update centroids as mean of assigned points.
+            let mut new_centroids = Array2::<f32>::zeros((k, ncols));
+            let mut counts = vec![0usize; k];
+            for (i, row) in data.outer_iter().enumerate() {
+                let c = assignments[i];
+                new_centroids.row_mut(c).zip_mut_with(&row, |n, &x| *n += x);
+                counts[c] += 1;
+            }
+            for (ci, &count) in counts.iter().enumerate() {
+                if count > 0 {
+                    new_centroids.row_mut(ci).mapv_inplace(|x| x / count as f32);
+                } else {
+                    let idx = rng.gen_range(0..nrows);
+                    new_centroids.row_mut(ci).assign(&data.row(idx));
+                }
+            }
+
+            let diff = (&centroids - &new_centroids).mapv(|x| x.abs()).sum();
+            if diff < 1e-4 {
+                break;
+            }
+            centroids = new_centroids;
+        }
+
+        Ok(assignments)
+    }
+}
+
+/// k-means++ seed selection shared by the numeric and generic engines.
+///
+/// Returns `k` point indices: the first uniformly at random, then each
+/// subsequent one sampled with probability proportional to its distance to the
+/// nearest already-chosen seed. `dist(a, b)` gives the dissimilarity between
+/// points `a` and `b`: the numeric `DataSet` path passes squared Euclidean
+/// distance whatever its assignment [`Metric`], and [`cluster`] passes
+/// [`Clusterable::distance`].
+fn kmeans_plus_plus<F: Fn(usize, usize) -> f32>(
+    n: usize,
+    k: usize,
+    rng: &mut impl Rng,
+    dist: F,
+) -> Vec<usize> {
+    let mut chosen = Vec::with_capacity(k);
+    chosen.push(rng.gen_range(0..n));
+
+    // Nearest distance of every point to an already-chosen seed.
+    let mut nearest = vec![f32::MAX; n];
+    for _ in 1..k {
+        let last = *chosen.last().unwrap();
+        let mut total = 0.0;
+        for (i, near) in nearest.iter_mut().enumerate() {
+            let d = dist(i, last);
+            if d < *near {
+                *near = d;
+            }
+            total += *near;
+        }
+
+        // Weighted draw over the cumulative distance distribution.
+        let pick = if total > 0.0 {
+            let target = rng.gen::<f32>() * total;
+            let mut acc = 0.0;
+            nearest
+                .iter()
+                .position(|&w| {
+                    acc += w;
+                    acc >= target
+                })
+                .unwrap_or(n - 1)
+        } else {
+            rng.gen_range(0..n)
+        };
+        chosen.push(pick);
+    }
+
+    chosen
+}
+
+/// A point type that k-means can cluster.
+///
+/// Implementing this trait for a custom type lets the generic [`cluster`]
+/// engine run over it with whatever dissimilarity and averaging rule makes
+/// sense for the domain (Euclidean, Manhattan, cosine, …) without changing the
+/// iteration logic.
+pub trait Clusterable {
+    /// Dissimilarity between `self` and `other`; smaller means closer.
+    fn distance(&self, other: &Self) -> f32;
+
+    /// The representative centre of a group of points, or `None` if empty.
+    fn centroid(points: &[Self]) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+/// Row-vector points clustered under (squared) Euclidean distance with the
+/// coordinate mean as centroid — the behaviour of the numeric `DataSet` path.
+impl Clusterable for Vec<f32> {
+    fn distance(&self, other: &Self) -> f32 {
+        self.iter().zip(other).fold(0.0, |d, (a, b)| d + (a - b).powi(2))
+    }
+
+    fn centroid(points: &[Self]) -> Option<Self> {
+        let n = points.len();
+        let d = points.first()?.len();
+        let mut acc = vec![0.0f32; d];
+        for p in points {
+            for (a, x) in acc.iter_mut().zip(p) {
+                *a += x;
+            }
+        }
+        for a in acc.iter_mut() {
+            *a /= n as f32;
+        }
+        Some(acc)
+    }
+}
+
+/// Generic k-means over any [`Clusterable`] point type.
+///
+/// Returns one cluster label per input point. Initial centres are seeded with
+/// `init`; an empty cluster is reseeded from a random point so a run always
+/// yields `k` centroids.
+pub fn cluster<T: Clusterable + Clone>(
+    points: &[T],
+    k: usize,
+    max_iter: usize,
+    init: InitMethod,
+) -> Vec<usize> {
+    let n = points.len();
+    if n < k || k == 0 {
+        return vec![0; n];
+    }
+
+    let mut rng = thread_rng();
+
+    // Seed the initial centres.
+    let mut centroids: Vec<T> = match init {
+        InitMethod::Random => {
+            let mut idx: Vec<usize> = (0..n).collect();
+            idx.shuffle(&mut rng);
+            idx.iter().take(k).map(|&i| points[i].clone()).collect()
+        }
+        InitMethod::KmeansPlusPlus => {
+            kmeans_plus_plus(n, k, &mut rng, |a, b| points[a].distance(&points[b]))
+                .iter()
+                .map(|&i| points[i].clone())
+                .collect()
+        }
+    };
+
+    let mut labels = vec![0usize; n];
+    for _ in 0..max_iter {
+        // Assign each point to its nearest centre.
+        let mut changed = false;
+        for (i, p) in points.iter().enumerate() {
+            let mut best = 0;
+            let mut best_d = f32::MAX;
+            for (ci, c) in centroids.iter().enumerate() {
+                let d = p.distance(c);
+                if d < best_d {
+                    best_d = d;
+                    best = ci;
+                }
+            }
+            if labels[i] != best {
+                changed = true;
+            }
+            labels[i] = best;
+        }
+
+        // Recompute each centre as the centroid of its members, reseeding any
+        // empty cluster from a random point so a run always yields `k` centres.
+        for (ci, centre) in centroids.iter_mut().enumerate() {
+            let members: Vec<T> = points
+                .iter()
+                .zip(&labels)
+                .filter(|&(_, &l)| l == ci)
+                .map(|(p, _)| p.clone())
+                .collect();
+            match T::centroid(&members) {
+                Some(c) => *centre = c,
+                None => *centre = points[rng.gen_range(0..n)].clone(),
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    labels
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ndarray::array;
 
     #[test]
     fn test_e_dist3() {
@@ -164,4 +690,123 @@ mod tests {
         let labels = ds.kmeans3d(2, 20).unwrap();
         assert_eq!(labels.len(), 4);
     }
+
+    #[test]
+    fn test_kmeans_general() {
+        // Four-dimensional points forming two clear clusters
+        let data = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.1, 0.0, 0.1, 0.0],
+            [9.0, 9.0, 9.0, 9.0],
+            [9.1, 9.1, 9.1, 9.1],
+        ];
+        let ds = DataSet {
+            data,
+            headers: None,
+        };
+        let labels = ds.kmeans(2, 4, 20, InitMethod::Random, Metric::Euclidean).unwrap();
+        assert_eq!(labels.len(), 4);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], labels[2]);
+    }
+
+    #[test]
+    fn test_kmeans_plusplus() {
+        let data = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.1, 0.0, 0.1, 0.0],
+            [9.0, 9.0, 9.0, 9.0],
+            [9.1, 9.1, 9.1, 9.1],
+        ];
+        let ds = DataSet {
+            data,
+            headers: None,
+        };
+        let labels = ds.kmeans(2, 4, 20, InitMethod::KmeansPlusPlus, Metric::Euclidean).unwrap();
+        assert_eq!(labels.len(), 4);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], labels[2]);
+    }
+
+    #[test]
+    fn test_equal_kmeans_balanced() {
+        // Three points near the origin, one far away: plain k-means would put
+        // three in one cluster, but the capacity cap of ceil(4/2)=2 forces a
+        // 2/2 split.
+        let data = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.1, 0.0, 0.1, 0.0],
+            [0.2, 0.1, 0.0, 0.0],
+            [9.0, 9.0, 9.0, 9.0],
+        ];
+        let ds = DataSet {
+            data,
+            headers: None,
+        };
+        let labels = ds.equal_kmeans(2, 4, 20, InitMethod::Random).unwrap();
+        assert_eq!(labels.len(), 4);
+        let mut counts = [0usize; 2];
+        for &l in &labels {
+            counts[l] += 1;
+        }
+        assert_eq!(counts[0], 2);
+        assert_eq!(counts[1], 2);
+    }
+
+    #[test]
+    fn test_kmeans_best_inertia() {
+        let data = array![
+            [0.0, 0.0, 0.0, 0.0],
+            [0.1, 0.0, 0.1, 0.0],
+            [9.0, 9.0, 9.0, 9.0],
+            [9.1, 9.1, 9.1, 9.1],
+        ];
+        let ds = DataSet {
+            data,
+            headers: None,
+        };
+        let res = ds.kmeans_best(2, 4, 20, InitMethod::KmeansPlusPlus, Metric::Euclidean, 5).unwrap();
+        assert_eq!(res.labels.len(), 4);
+        assert_eq!(res.centroids.nrows(), 2);
+        // The two tight clusters leave only tiny intra-cluster spread.
+        assert!(res.inertia < 1.0);
+    }
+
+    #[test]
+    fn test_cluster_generic_vec() {
+        let points = vec![
+            vec![0.0, 0.0],
+            vec![0.1, 0.1],
+            vec![9.0, 9.0],
+            vec![9.1, 9.1],
+        ];
+        let labels = cluster(&points, 2, 20, InitMethod::KmeansPlusPlus);
+        assert_eq!(labels.len(), 4);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], labels[2]);
+    }
+
+    #[test]
+    fn test_kmeans_cosine_directions() {
+        // Two groups pointing in different directions but at different scales;
+        // cosine should group by direction, not magnitude.
+        let data = array![
+            [1.0, 0.0, 0.0, 0.0],
+            [5.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 7.0, 0.0, 0.0],
+        ];
+        let ds = DataSet {
+            data,
+            headers: None,
+        };
+        let labels = ds.kmeans(2, 4, 20, InitMethod::KmeansPlusPlus, Metric::Cosine).unwrap();
+        assert_eq!(labels.len(), 4);
+        assert_eq!(labels[0], labels[1]);
+        assert_eq!(labels[2], labels[3]);
+        assert_ne!(labels[0], labels[2]);
+    }
 }
\ No newline at end of file